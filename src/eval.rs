@@ -10,6 +10,8 @@ pub enum ShellError {
     Unsupported(Span),
     InternalError(String),
     VariableNotFound(Span),
+    DivisionByZero(Span),
+    ArithmeticOverflow(Span),
 }
 
 #[derive(Debug, Clone)]
@@ -19,6 +21,7 @@ pub enum Value {
     Float { val: f64, span: Span },
     String { val: String, span: Span },
     List { val: Vec<Value>, span: Span },
+    Record { cols: Vec<String>, vals: Vec<Value>, span: Span },
     Block { val: BlockId, span: Span },
     Nothing { span: Span },
 }
@@ -38,6 +41,7 @@ impl Value {
             Value::Float { span, .. } => *span,
             Value::String { span, .. } => *span,
             Value::List { span, .. } => *span,
+            Value::Record { span, .. } => *span,
             Value::Block { span, .. } => *span,
             Value::Nothing { span, .. } => *span,
         }
@@ -52,6 +56,27 @@ impl PartialEq for Value {
             (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => lhs == rhs,
             (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => lhs == rhs,
             (Value::List { val: l1, .. }, Value::List { val: l2, .. }) => l1 == l2,
+            (
+                Value::Record {
+                    cols: cols1,
+                    vals: vals1,
+                    ..
+                },
+                Value::Record {
+                    cols: cols2,
+                    vals: vals2,
+                    ..
+                },
+            ) => {
+                cols1.len() == cols2.len()
+                    && cols1.iter().zip(vals1).all(|(col, val)| {
+                        cols2
+                            .iter()
+                            .position(|other_col| other_col == col)
+                            .map(|idx| &vals2[idx] == val)
+                            .unwrap_or(false)
+                    })
+            }
             (Value::Block { val: b1, .. }, Value::Block { val: b2, .. }) => b1 == b2,
             _ => false,
         }
@@ -72,6 +97,7 @@ impl Display for Value {
             }
             Value::String { val, .. } => write!(f, "{}", val),
             Value::List { .. } => write!(f, "<list>"),
+            Value::Record { .. } => write!(f, "<record>"),
             Value::Block { .. } => write!(f, "<block>"),
             Value::Nothing { .. } => write!(f, ""),
         }
@@ -105,6 +131,320 @@ impl Value {
             _ => Err(ShellError::Mismatch("addition".into(), self.span())),
         }
     }
+
+    pub fn sub(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Int {
+                val: lhs - rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: *lhs as f64 - *rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Float {
+                val: *lhs - *rhs as f64,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: lhs - rhs,
+                span: Span::unknown(),
+            }),
+
+            _ => Err(ShellError::Mismatch("subtraction".into(), self.span())),
+        }
+    }
+
+    pub fn mul(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Int {
+                val: lhs * rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: *lhs as f64 * *rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Float {
+                val: *lhs * *rhs as f64,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: lhs * rhs,
+                span: Span::unknown(),
+            }),
+
+            _ => Err(ShellError::Mismatch("multiplication".into(), self.span())),
+        }
+    }
+
+    pub fn div(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, span }) => {
+                if *rhs == 0 {
+                    return Err(ShellError::DivisionByZero(*span));
+                }
+                match (lhs.checked_div(*rhs), lhs.checked_rem(*rhs)) {
+                    (Some(val), Some(0)) => Ok(Value::Int {
+                        val,
+                        span: Span::unknown(),
+                    }),
+                    (Some(_), Some(_)) => Ok(Value::Float {
+                        val: *lhs as f64 / *rhs as f64,
+                        span: Span::unknown(),
+                    }),
+                    _ => Err(ShellError::ArithmeticOverflow(*span)),
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, span }) => {
+                if *rhs == 0.0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: *lhs as f64 / *rhs,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, span }) => {
+                if *rhs == 0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: *lhs / *rhs as f64,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, span }) => {
+                if *rhs == 0.0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: lhs / rhs,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+
+            _ => Err(ShellError::Mismatch("division".into(), self.span())),
+        }
+    }
+
+    pub fn modulo(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, span }) => {
+                if *rhs == 0 {
+                    return Err(ShellError::DivisionByZero(*span));
+                }
+                match lhs.checked_rem(*rhs) {
+                    Some(val) => Ok(Value::Int {
+                        val,
+                        span: Span::unknown(),
+                    }),
+                    None => Err(ShellError::ArithmeticOverflow(*span)),
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, span }) => {
+                if *rhs == 0.0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: *lhs as f64 % *rhs,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, span }) => {
+                if *rhs == 0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: *lhs % *rhs as f64,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, span }) => {
+                if *rhs == 0.0 {
+                    Err(ShellError::DivisionByZero(*span))
+                } else {
+                    Ok(Value::Float {
+                        val: lhs % rhs,
+                        span: Span::unknown(),
+                    })
+                }
+            }
+
+            _ => Err(ShellError::Mismatch("modulo".into(), self.span())),
+        }
+    }
+
+    pub fn pow(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if *rhs < 0 {
+                    Ok(Value::Float {
+                        val: (*lhs as f64).powi(*rhs as i32),
+                        span: Span::unknown(),
+                    })
+                } else {
+                    match u32::try_from(*rhs).ok().and_then(|rhs| lhs.checked_pow(rhs)) {
+                        Some(val) => Ok(Value::Int {
+                            val,
+                            span: Span::unknown(),
+                        }),
+                        None => Ok(Value::Float {
+                            val: (*lhs as f64).powi(*rhs as i32),
+                            span: Span::unknown(),
+                        }),
+                    }
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: (*lhs as f64).powf(*rhs),
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Float {
+                val: lhs.powf(*rhs as f64),
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
+                val: lhs.powf(*rhs),
+                span: Span::unknown(),
+            }),
+
+            _ => Err(ShellError::Mismatch("exponentiation".into(), self.span())),
+        }
+    }
+
+    pub fn eq(&self, rhs: &Value) -> Result<Value, ShellError> {
+        match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Bool {
+                val: lhs == rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Bool {
+                val: *lhs as f64 == *rhs,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::Bool {
+                val: *lhs == *rhs as f64,
+                span: Span::unknown(),
+            }),
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Bool {
+                val: lhs == rhs,
+                span: Span::unknown(),
+            }),
+            (Value::String { .. }, Value::String { .. }) | (Value::Bool { .. }, Value::Bool { .. }) => {
+                Ok(Value::Bool {
+                    val: self == rhs,
+                    span: Span::unknown(),
+                })
+            }
+
+            _ => Err(ShellError::Mismatch("equality".into(), self.span())),
+        }
+    }
+
+    pub fn ne(&self, rhs: &Value) -> Result<Value, ShellError> {
+        self.eq(rhs).map(|val| match val {
+            Value::Bool { val, span } => Value::Bool { val: !val, span },
+            other => other,
+        })
+    }
+
+    pub fn lt(&self, rhs: &Value) -> Result<Value, ShellError> {
+        self.compare(rhs, "less than", |ord| ord.is_lt())
+    }
+
+    pub fn lte(&self, rhs: &Value) -> Result<Value, ShellError> {
+        self.compare(rhs, "less than or equal", |ord| ord.is_le())
+    }
+
+    pub fn gt(&self, rhs: &Value) -> Result<Value, ShellError> {
+        self.compare(rhs, "greater than", |ord| ord.is_gt())
+    }
+
+    pub fn gte(&self, rhs: &Value) -> Result<Value, ShellError> {
+        self.compare(rhs, "greater than or equal", |ord| ord.is_ge())
+    }
+
+    fn compare(
+        &self,
+        rhs: &Value,
+        op_name: &str,
+        f: impl Fn(std::cmp::Ordering) -> bool,
+    ) -> Result<Value, ShellError> {
+        let ord = match (self, rhs) {
+            (Value::Int { val: lhs, .. }, Value::Int { val: rhs, .. }) => lhs.partial_cmp(rhs),
+            (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                (*lhs as f64).partial_cmp(rhs)
+            }
+            (Value::Float { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                lhs.partial_cmp(&(*rhs as f64))
+            }
+            (Value::Float { val: lhs, .. }, Value::Float { val: rhs, .. }) => lhs.partial_cmp(rhs),
+            (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => {
+                lhs.partial_cmp(rhs)
+            }
+
+            _ => return Err(ShellError::Mismatch(op_name.into(), self.span())),
+        };
+
+        match ord {
+            Some(ord) => Ok(Value::Bool {
+                val: f(ord),
+                span: Span::unknown(),
+            }),
+            None => Err(ShellError::Mismatch(op_name.into(), self.span())),
+        }
+    }
+
+    pub fn contains(&self, needle: &Value) -> Result<bool, ShellError> {
+        match self {
+            Value::List { val, .. } => Ok(val.iter().any(|element| element == needle)),
+            Value::String { val, .. } => {
+                let needle = needle.as_string()?;
+                Ok(val.contains(&needle))
+            }
+            _ => Err(ShellError::Mismatch("container".into(), self.span())),
+        }
+    }
+
+    pub fn and(
+        &self,
+        rhs: impl FnOnce() -> Result<Value, ShellError>,
+    ) -> Result<Value, ShellError> {
+        match self {
+            Value::Bool { val: false, span } => Ok(Value::Bool {
+                val: false,
+                span: *span,
+            }),
+            Value::Bool { .. } => match rhs()? {
+                Value::Bool { val, span } => Ok(Value::Bool { val, span }),
+                other => Err(ShellError::Mismatch("bool".into(), other.span())),
+            },
+            _ => Err(ShellError::Mismatch("bool".into(), self.span())),
+        }
+    }
+
+    pub fn or(
+        &self,
+        rhs: impl FnOnce() -> Result<Value, ShellError>,
+    ) -> Result<Value, ShellError> {
+        match self {
+            Value::Bool { val: true, span } => Ok(Value::Bool {
+                val: true,
+                span: *span,
+            }),
+            Value::Bool { .. } => match rhs()? {
+                Value::Bool { val, span } => Ok(Value::Bool { val, span }),
+                other => Err(ShellError::Mismatch("bool".into(), other.span())),
+            },
+            _ => Err(ShellError::Mismatch("bool".into(), self.span())),
+        }
+    }
 }
 
 pub struct State<'a> {
@@ -182,6 +522,182 @@ impl Stack {
     }
 }
 
+impl Expression {
+    /// Recursively visits this expression and everything it contains, calling `f` on
+    /// every node encountered. Returning `false` from `f` stops the walk immediately;
+    /// the return value propagates that signal back up to the caller.
+    pub fn walk(&self, state: &ParserState, f: &mut impl FnMut(&Expression) -> bool) -> bool {
+        if !f(self) {
+            return false;
+        }
+
+        match &self.expr {
+            Expr::BinaryOp(lhs, op, rhs) => {
+                lhs.walk(state, f) && op.walk(state, f) && rhs.walk(state, f)
+            }
+            Expr::Call(call) => call.positional.iter().all(|arg| arg.walk(state, f)),
+            Expr::List(items) => items.iter().all(|item| item.walk(state, f)),
+            Expr::Record(pairs) => pairs
+                .iter()
+                .all(|(col, val)| col.walk(state, f) && val.walk(state, f)),
+            Expr::Keyword(_, _, expr) => expr.walk(state, f),
+            Expr::Subexpression(block_id) | Expr::Block(block_id) => {
+                state.get_block(*block_id).walk(state, f)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl Block {
+    /// Walks every top-level expression statement in the block, in order. See
+    /// [`Expression::walk`] for how the short-circuiting works.
+    pub fn walk(&self, state: &ParserState, f: &mut impl FnMut(&Expression) -> bool) -> bool {
+        for stmt in &self.stmts {
+            if let Statement::Expression(expression) = stmt {
+                if !expression.walk(state, f) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn literal_to_value(expr: &Expression) -> Option<Value> {
+    match &expr.expr {
+        Expr::Int(val) => Some(Value::Int {
+            val: *val,
+            span: expr.span,
+        }),
+        Expr::Float(val) => Some(Value::Float {
+            val: *val,
+            span: expr.span,
+        }),
+        Expr::Bool(val) => Some(Value::Bool {
+            val: *val,
+            span: expr.span,
+        }),
+        Expr::String(val) => Some(Value::String {
+            val: val.clone(),
+            span: expr.span,
+        }),
+        _ => None,
+    }
+}
+
+fn value_to_literal_expr(value: Value) -> Option<Expr> {
+    match value {
+        Value::Int { val, .. } => Some(Expr::Int(val)),
+        Value::Float { val, .. } => Some(Expr::Float(val)),
+        Value::Bool { val, .. } => Some(Expr::Bool(val)),
+        Value::String { val, .. } => Some(Expr::String(val)),
+        _ => None,
+    }
+}
+
+fn fold_binary_op(op: &Operator, lhs: &Value, rhs: &Value) -> Result<Value, ShellError> {
+    match op {
+        Operator::Plus => lhs.add(rhs),
+        Operator::Minus => lhs.sub(rhs),
+        Operator::Multiply => lhs.mul(rhs),
+        Operator::Divide => lhs.div(rhs),
+        Operator::Modulo => lhs.modulo(rhs),
+        Operator::Pow => lhs.pow(rhs),
+        Operator::Equal => lhs.eq(rhs),
+        Operator::NotEqual => lhs.ne(rhs),
+        Operator::LessThan => lhs.lt(rhs),
+        Operator::LessThanOrEqual => lhs.lte(rhs),
+        Operator::GreaterThan => lhs.gt(rhs),
+        Operator::GreaterThanOrEqual => lhs.gte(rhs),
+        Operator::And => lhs.and(|| Ok(rhs.clone())),
+        Operator::Or => lhs.or(|| Ok(rhs.clone())),
+        Operator::In => rhs.contains(lhs).map(|val| Value::Bool {
+            val,
+            span: Span::unknown(),
+        }),
+        Operator::NotIn => rhs.contains(lhs).map(|val| Value::Bool {
+            val: !val,
+            span: Span::unknown(),
+        }),
+    }
+}
+
+/// Folds literal-only subexpressions of `expr` in place, bottom-up, so nested
+/// constant expressions like `(1 + 2) + 3` fully collapse before the outer node
+/// is considered. `Value::div`/`Value::modulo` already return `Err` rather than
+/// panicking for a zero literal divisor (or an overflowing `i64::MIN / -1`), so
+/// those cases are naturally left intact below; `pow` is panic-safe as well
+/// (checked, promoting to a float on overflow or a negative exponent) so it
+/// folds like any other operator.
+fn fold_expression(state: &ParserState, expr: &mut Expression) {
+    match &mut expr.expr {
+        Expr::BinaryOp(lhs, op, rhs) => {
+            fold_expression(state, lhs);
+            fold_expression(state, rhs);
+
+            if let (Expr::Operator(operator), Some(lhs_val), Some(rhs_val)) =
+                (&op.expr, literal_to_value(lhs), literal_to_value(rhs))
+            {
+                if let Ok(folded) = fold_binary_op(operator, &lhs_val, &rhs_val) {
+                    if let Some(literal) = value_to_literal_expr(folded) {
+                        expr.expr = literal;
+                    }
+                }
+            }
+        }
+        Expr::List(items) => {
+            for item in items {
+                fold_expression(state, item);
+            }
+        }
+        Expr::Record(pairs) => {
+            for (col, val) in pairs {
+                fold_expression(state, col);
+                fold_expression(state, val);
+            }
+        }
+        Expr::Keyword(_, _, inner) => fold_expression(state, inner),
+        Expr::Call(call) => {
+            for arg in &mut call.positional {
+                fold_expression(state, arg);
+            }
+        }
+        Expr::Subexpression(block_id) => {
+            let folded = optimize_block(state, state.get_block(*block_id));
+            if let [Statement::Expression(inner)] = folded.stmts.as_slice() {
+                if literal_to_value(inner).is_some() {
+                    expr.expr = inner.expr.clone();
+                    return;
+                }
+            }
+            *block_id = state.add_block(folded);
+        }
+        Expr::Block(block_id) => {
+            let folded = optimize_block(state, state.get_block(*block_id));
+            *block_id = state.add_block(folded);
+        }
+        _ => {}
+    }
+}
+
+/// Runs the constant-folding pass over `block`, returning an optimized copy with
+/// literal-only subexpressions collapsed. Meant to be called once between parsing
+/// and `eval_block` so hot loops aren't re-evaluating invariant literal expressions
+/// on every pass. Descends into blocks referenced by `Expr::Subexpression`/
+/// `Expr::Block` (resolved through `state`, the same way `Expression::walk` does)
+/// so nested block bodies — like a `for` loop's invariant condition — get folded
+/// too, not just the top-level statements.
+pub fn optimize_block(state: &ParserState, block: &Block) -> Block {
+    let mut block = block.clone();
+    for stmt in &mut block.stmts {
+        if let Statement::Expression(expression) = stmt {
+            fold_expression(state, expression);
+        }
+    }
+    block
+}
+
 pub fn eval_operator(
     _state: &State,
     _stack: Stack,
@@ -315,7 +831,7 @@ fn eval_call(state: &State, stack: Stack, call: &Call) -> Result<Value, ShellErr
         let keyword_expr = call.positional[1]
             .as_keyword()
             .expect("internal error: missing keyword");
-        let end_val = eval_expression(state, stack.clone(), keyword_expr)?;
+        let iterable = eval_expression(state, stack.clone(), keyword_expr)?;
 
         let block = call.positional[2]
             .as_block()
@@ -324,25 +840,55 @@ fn eval_call(state: &State, stack: Stack, call: &Call) -> Result<Value, ShellErr
 
         let stack = stack.enter_scope();
 
-        let mut x = Value::Int {
-            val: 0,
-            span: Span::unknown(),
-        };
-
-        loop {
-            if x == end_val {
-                break;
-            } else {
-                stack.add_var(var_id, x.clone());
-                eval_block(state, stack.clone(), block)?;
+        match iterable {
+            Value::List { val, .. } => {
+                for item in val {
+                    stack.add_var(var_id, item);
+                    eval_block(state, stack.clone(), block)?;
+                }
             }
-            if let Value::Int { ref mut val, .. } = x {
-                *val += 1
+            Value::String { val, span } => {
+                for c in val.chars() {
+                    stack.add_var(
+                        var_id,
+                        Value::String {
+                            val: c.to_string(),
+                            span,
+                        },
+                    );
+                    eval_block(state, stack.clone(), block)?;
+                }
+            }
+            Value::Int { val: end, span } => {
+                for i in 0..end {
+                    stack.add_var(var_id, Value::Int { val: i, span });
+                    eval_block(state, stack.clone(), block)?;
+                }
             }
+            _ => return Err(ShellError::Mismatch("iterable".into(), iterable.span())),
         }
+
         Ok(Value::Nothing {
             span: call.positional[0].span,
         })
+    } else if decl.signature.name == "columns" {
+        let record = eval_expression(state, stack, &call.positional[0])?;
+        match record {
+            Value::Record { cols, span, .. } => Ok(Value::List {
+                val: cols
+                    .into_iter()
+                    .map(|col| Value::String { val: col, span })
+                    .collect(),
+                span,
+            }),
+            _ => Err(ShellError::Mismatch("record".into(), record.span())),
+        }
+    } else if decl.signature.name == "values" {
+        let record = eval_expression(state, stack, &call.positional[0])?;
+        match record {
+            Value::Record { vals, span, .. } => Ok(Value::List { val: vals, span }),
+            _ => Err(ShellError::Mismatch("record".into(), record.span())),
+        }
     } else if decl.signature.name == "vars" {
         state.parser_state.print_vars();
         Ok(Value::Nothing {
@@ -389,13 +935,40 @@ pub fn eval_expression(
         Expr::ExternalCall(_, _) => Err(ShellError::Unsupported(expr.span)),
         Expr::Operator(_) => Ok(Value::Nothing { span: expr.span }),
         Expr::BinaryOp(lhs, op, rhs) => {
-            let lhs = eval_expression(state, stack.clone(), lhs)?;
             let op = eval_operator(state, stack.clone(), op)?;
-            let rhs = eval_expression(state, stack, rhs)?;
+            let lhs = eval_expression(state, stack.clone(), lhs)?;
 
             match op {
-                Operator::Plus => lhs.add(&rhs),
-                _ => Ok(Value::Nothing { span: expr.span }),
+                Operator::And => lhs.and(|| eval_expression(state, stack, rhs)),
+                Operator::Or => lhs.or(|| eval_expression(state, stack, rhs)),
+                _ => {
+                    let rhs = eval_expression(state, stack, rhs)?;
+
+                    match op {
+                        Operator::In => rhs.contains(&lhs).map(|val| Value::Bool {
+                            val,
+                            span: expr.span,
+                        }),
+                        Operator::NotIn => rhs.contains(&lhs).map(|val| Value::Bool {
+                            val: !val,
+                            span: expr.span,
+                        }),
+                        Operator::Plus => lhs.add(&rhs),
+                        Operator::Minus => lhs.sub(&rhs),
+                        Operator::Multiply => lhs.mul(&rhs),
+                        Operator::Divide => lhs.div(&rhs),
+                        Operator::Modulo => lhs.modulo(&rhs),
+                        Operator::Pow => lhs.pow(&rhs),
+                        Operator::Equal => lhs.eq(&rhs),
+                        Operator::NotEqual => lhs.ne(&rhs),
+                        Operator::LessThan => lhs.lt(&rhs),
+                        Operator::LessThanOrEqual => lhs.lte(&rhs),
+                        Operator::GreaterThan => lhs.gt(&rhs),
+                        Operator::GreaterThanOrEqual => lhs.gte(&rhs),
+                        Operator::And | Operator::Or => unreachable!("handled above"),
+                        _ => Ok(Value::Nothing { span: expr.span }),
+                    }
+                }
             }
         }
 
@@ -419,6 +992,21 @@ pub fn eval_expression(
                 span: expr.span,
             })
         }
+        Expr::Record(pairs) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (col, val) in pairs {
+                let col = eval_expression(state, stack.clone(), col)?.as_string()?;
+                let val = eval_expression(state, stack.clone(), val)?;
+                cols.push(col);
+                vals.push(val);
+            }
+            Ok(Value::Record {
+                cols,
+                vals,
+                span: expr.span,
+            })
+        }
         Expr::Table(_, _) => Err(ShellError::Unsupported(expr.span)),
         Expr::Keyword(_, _, expr) => eval_expression(state, stack, expr),
         Expr::String(s) => Ok(Value::String {